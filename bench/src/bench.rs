@@ -1,5 +1,5 @@
 use ndcopy::ndshape::{ConstShape2u32, ConstShape3u32};
-use ndcopy::{copy2, copy3};
+use ndcopy::{copy2, copy3, copy3_copy};
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 
@@ -65,5 +65,36 @@ pub fn bench_copy3(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_copy2, bench_copy3);
+pub fn bench_copy3_copy(c: &mut Criterion) {
+    let shape = ConstShape3u32::<100, 100, 100>;
+    let src = [1u8; 100 * 100 * 100];
+    let mut dst = [0u8; 100 * 100 * 100];
+
+    let mut group = c.benchmark_group("bench_copy3_copy");
+    for &copy_width in [8, 16, 32, 64].iter() {
+        let copy_volume = copy_width * copy_width * copy_width;
+
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("volume={}", copy_volume)),
+            &(),
+            |b, _| {
+                b.iter(|| {
+                    copy3_copy(
+                        [copy_width; 3],
+                        &src,
+                        &shape,
+                        [1, 2, 3],
+                        &mut dst,
+                        &shape,
+                        [3, 4, 5],
+                    );
+                    black_box(&dst);
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_copy2, bench_copy3, bench_copy3_copy);
 criterion_main!(benches);
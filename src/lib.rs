@@ -32,7 +32,448 @@ pub use ndshape;
 
 use ndshape::Shape;
 
-/// Copy 2-dimensional data from `src` to `dst`.
+/// A storage backend that can copy and fill contiguous runs of elements addressed by a linear
+/// offset and a run length, rather than by direct slicing.
+///
+/// `copy_nd`/`fill_nd` compute `(offset, run_length)` tuples from `Shape::linearize` and dispatch
+/// each row transfer through this trait. The CPU implementation (for `[T]`) just slices and calls
+/// `clone_from_slice`/`fill`. `copy_nd`/`fill_nd` take `&[T]`/`&mut [T]` arguments and so always
+/// use the `[T]` implementation; another implementor of `CopyBackend` (e.g. a GPU buffer) cannot be
+/// passed to them.
+pub trait CopyBackend<T> {
+    /// Copy `run_length` elements from `src` starting at `src_offset` to `self` starting at
+    /// `dst_offset`.
+    fn copy_run(&mut self, dst_offset: usize, src: &Self, src_offset: usize, run_length: usize);
+
+    /// Fill `run_length` elements of `self` starting at `dst_offset` with `value`.
+    fn fill_run(&mut self, dst_offset: usize, value: T, run_length: usize);
+}
+
+impl<T: Clone> CopyBackend<T> for [T] {
+    #[inline]
+    fn copy_run(&mut self, dst_offset: usize, src: &Self, src_offset: usize, run_length: usize) {
+        self[dst_offset..dst_offset + run_length]
+            .clone_from_slice(&src[src_offset..src_offset + run_length]);
+    }
+
+    #[inline]
+    fn fill_run(&mut self, dst_offset: usize, value: T, run_length: usize) {
+        self[dst_offset..dst_offset + run_length].fill(value);
+    }
+}
+
+/// Copy N-dimensional data from `src` to `dst`.
+///
+/// This is the general form of `copy2`/`copy3`/`copy4`, parameterized over the number of
+/// dimensions `N`. It copies row-by-row along axis 0: for every coordinate in the remaining
+/// `N - 1` axes of `copy_shape`, a single run of `copy_shape[0]` elements is transferred via
+/// [`CopyBackend::copy_run`].
+///
+/// - `copy_shape`: Dimensions of the extent to be copied.
+/// - `src`: The source slice.
+/// - `src_shape`: A `Shape<u32, N>` for the entire `src` slice.
+/// - `src_start`: The starting offset to copy from `src`.
+/// - `dst`: The destination slice.
+/// - `dst_shape`: A `Shape<u32, N>` for the entire `dst` slice.
+/// - `dst_start`: The starting offset to copy into `dst`.
+#[inline]
+pub fn copy_nd<T, Src, Dst, const N: usize>(
+    copy_shape: [u32; N],
+    src: &[T],
+    src_shape: &Src,
+    src_start: [u32; N],
+    dst: &mut [T],
+    dst_shape: &Dst,
+    dst_start: [u32; N],
+) where
+    T: Clone,
+    Src: Shape<u32, N>,
+    Dst: Shape<u32, N>,
+{
+    let row_length = copy_shape[0] as usize;
+    let num_rows: u32 = copy_shape[1..].iter().product();
+
+    // Odometer-style cursor over axes 1..N; axis 0 is handled by the row transfer below.
+    let mut cursor = [0u32; N];
+    for _ in 0..num_rows {
+        let mut src_coords = src_start;
+        let mut dst_coords = dst_start;
+        for axis in 1..N {
+            src_coords[axis] = src_start[axis] + cursor[axis];
+            dst_coords[axis] = dst_start[axis] + cursor[axis];
+        }
+
+        let src_row_start = src_shape.linearize(src_coords) as usize;
+        let dst_row_start = dst_shape.linearize(dst_coords) as usize;
+
+        dst.copy_run(dst_row_start, src, src_row_start, row_length);
+
+        for axis in (1..N).rev() {
+            cursor[axis] += 1;
+            if cursor[axis] < copy_shape[axis] {
+                break;
+            }
+            cursor[axis] = 0;
+        }
+    }
+}
+
+#[test]
+fn test_copy_nd_matches_copy3() {
+    use ndshape::Shape3u32;
+
+    let src_shape = Shape3u32::new([10, 11, 12]);
+    const SRC_SIZE: usize = 10 * 11 * 12;
+    let src = [1; SRC_SIZE];
+
+    let dst_shape = Shape3u32::new([11, 12, 13]);
+    const DST_SIZE: usize = 11 * 12 * 13;
+    let mut dst = [0; DST_SIZE];
+
+    copy_nd(
+        [2, 3, 4],
+        &src,
+        &src_shape,
+        [3, 4, 5],
+        &mut dst,
+        &dst_shape,
+        [4, 5, 6],
+    );
+
+    for z in 6..6 + 4 {
+        for y in 5..5 + 3 {
+            for x in 4..4 + 2 {
+                let i = dst_shape.linearize([x, y, z]) as usize;
+                assert_eq!(1, dst[i]);
+                dst[i] = 0;
+            }
+        }
+    }
+    for i in 0..DST_SIZE {
+        assert_eq!(dst[i], 0);
+    }
+}
+
+/// A row-major runtime shape, generic over the number of dimensions. `ndshape` only provides
+/// concrete 2D/3D/4D shapes, so this stands in for a 5D one to demonstrate that `copy_nd` has no
+/// dimensional ceiling.
+#[cfg(test)]
+struct RowMajorShape<const N: usize> {
+    dims: [u32; N],
+    strides: [u32; N],
+}
+
+#[cfg(test)]
+impl<const N: usize> RowMajorShape<N> {
+    fn new(dims: [u32; N]) -> Self {
+        let mut strides = [1; N];
+        for axis in 1..N {
+            strides[axis] = strides[axis - 1] * dims[axis - 1];
+        }
+        Self { dims, strides }
+    }
+}
+
+#[cfg(test)]
+impl<const N: usize> Shape<u32, N> for RowMajorShape<N> {
+    fn size(&self) -> u32 {
+        self.dims.iter().product()
+    }
+
+    fn linearize(&self, p: [u32; N]) -> u32 {
+        (0..N).map(|axis| p[axis] * self.strides[axis]).sum()
+    }
+
+    fn delinearize(&self, mut i: u32) -> [u32; N] {
+        let mut p = [0; N];
+        for axis in (0..N).rev() {
+            p[axis] = i / self.strides[axis];
+            i -= p[axis] * self.strides[axis];
+        }
+        p
+    }
+}
+
+#[test]
+fn test_copy_nd_5d() {
+    let src_shape = RowMajorShape::new([6, 6, 6, 6, 6]);
+    const SRC_SIZE: usize = 6 * 6 * 6 * 6 * 6;
+    let src = [1; SRC_SIZE];
+
+    let dst_shape = RowMajorShape::new([6, 6, 6, 6, 6]);
+    let mut dst = [0; SRC_SIZE];
+
+    copy_nd(
+        [2, 2, 2, 2, 2],
+        &src,
+        &src_shape,
+        [0, 0, 0, 0, 0],
+        &mut dst,
+        &dst_shape,
+        [1, 1, 1, 1, 1],
+    );
+
+    for v in 1..1 + 2 {
+        for u in 1..1 + 2 {
+            for z in 1..1 + 2 {
+                for y in 1..1 + 2 {
+                    for x in 1..1 + 2 {
+                        let i = dst_shape.linearize([x, y, z, u, v]) as usize;
+                        assert_eq!(1, dst[i]);
+                        dst[i] = 0;
+                    }
+                }
+            }
+        }
+    }
+    for i in 0..SRC_SIZE {
+        assert_eq!(dst[i], 0);
+    }
+}
+
+/// Fill an N-dimensional extent of `dst` with `value`.
+///
+/// This is the general form of `fill2`/`fill3`/`fill4`, parameterized over the number of
+/// dimensions `N`. See [`copy_nd`] for the row-by-row addressing scheme.
+///
+/// - `fill_shape`: Dimensions of the extent to be filled.
+/// - `value`: The value to write.
+/// - `dst`: The destination slice.
+/// - `dst_shape`: A `Shape<u32, N>` for the entire `dst` slice.
+/// - `dst_start`: The starting offset to fill into `dst`.
+#[inline]
+pub fn fill_nd<T, Dst, const N: usize>(
+    fill_shape: [u32; N],
+    value: T,
+    dst: &mut [T],
+    dst_shape: &Dst,
+    dst_start: [u32; N],
+) where
+    T: Clone,
+    Dst: Shape<u32, N>,
+{
+    let row_length = fill_shape[0] as usize;
+    let num_rows: u32 = fill_shape[1..].iter().product();
+
+    let mut cursor = [0u32; N];
+    for _ in 0..num_rows {
+        let mut dst_coords = dst_start;
+        for axis in 1..N {
+            dst_coords[axis] = dst_start[axis] + cursor[axis];
+        }
+
+        let dst_row_start = dst_shape.linearize(dst_coords) as usize;
+
+        dst.fill_run(dst_row_start, value.clone(), row_length);
+
+        for axis in (1..N).rev() {
+            cursor[axis] += 1;
+            if cursor[axis] < fill_shape[axis] {
+                break;
+            }
+            cursor[axis] = 0;
+        }
+    }
+}
+
+/// Copy N-dimensional data between `src` and `dst` whose axis-0 elements need not be contiguous.
+///
+/// This is the strided counterpart to [`copy_nd`]: instead of deriving offsets from a `Shape`,
+/// callers supply `src_strides`/`dst_strides` directly (in elements, not bytes), following the
+/// `cudaMemcpy2D`-style pitched-copy convention. When the axis-0 stride is `1` on both sides, this
+/// still takes the `clone_from_slice` fast path; otherwise it falls back to copying one element at
+/// a time along axis 0. This supports views, transposed tensors, and sub-volumes with padded
+/// ("pitched") rows, without first materializing a contiguous copy.
+///
+/// - `copy_shape`: Dimensions of the extent to be copied.
+/// - `src`: The source slice.
+/// - `src_start`: The starting offset to copy from `src`, in elements.
+/// - `src_strides`: The stride of each axis in `src`, in elements.
+/// - `dst`: The destination slice.
+/// - `dst_start`: The starting offset to copy into `dst`, in elements.
+/// - `dst_strides`: The stride of each axis in `dst`, in elements.
+#[inline]
+pub fn copy_nd_strided<T, const N: usize>(
+    copy_shape: [u32; N],
+    src: &[T],
+    src_start: [u32; N],
+    src_strides: [u32; N],
+    dst: &mut [T],
+    dst_start: [u32; N],
+    dst_strides: [u32; N],
+) where
+    T: Clone,
+{
+    let row_length = copy_shape[0];
+    let num_rows: u32 = copy_shape[1..].iter().product();
+
+    // Odometer-style cursor over axes 1..N; axis 0 is handled by the row transfer below.
+    let mut cursor = [0u32; N];
+    for _ in 0..num_rows {
+        let mut src_offset = src_start[0] * src_strides[0];
+        let mut dst_offset = dst_start[0] * dst_strides[0];
+        for axis in 1..N {
+            src_offset += (src_start[axis] + cursor[axis]) * src_strides[axis];
+            dst_offset += (dst_start[axis] + cursor[axis]) * dst_strides[axis];
+        }
+
+        if src_strides[0] == 1 && dst_strides[0] == 1 {
+            let src_row_start = src_offset as usize;
+            let src_row_end = src_row_start + row_length as usize;
+
+            let dst_row_start = dst_offset as usize;
+            let dst_row_end = dst_row_start + row_length as usize;
+
+            dst[dst_row_start..dst_row_end].clone_from_slice(&src[src_row_start..src_row_end]);
+        } else {
+            for x in 0..row_length {
+                let src_index = (src_offset + x * src_strides[0]) as usize;
+                let dst_index = (dst_offset + x * dst_strides[0]) as usize;
+                dst[dst_index] = src[src_index].clone();
+            }
+        }
+
+        for axis in (1..N).rev() {
+            cursor[axis] += 1;
+            if cursor[axis] < copy_shape[axis] {
+                break;
+            }
+            cursor[axis] = 0;
+        }
+    }
+}
+
+/// Copy 2-dimensional data from `src` to `dst`, where axis-0 elements may be strided.
+///
+/// See [`copy_nd_strided`] for the general N-dimensional implementation.
+///
+/// - `copy_shape`: Dimensions of the extent to be copied.
+/// - `src`: The source slice.
+/// - `src_start`: The starting 2D offset to copy from `src`, in elements.
+/// - `src_strides`: The stride of each axis in `src`, in elements.
+/// - `dst`: The destination slice.
+/// - `dst_start`: The starting 2D offset to copy into `dst`, in elements.
+/// - `dst_strides`: The stride of each axis in `dst`, in elements.
+#[inline]
+pub fn copy2_strided<T>(
+    copy_shape: [u32; 2],
+    src: &[T],
+    src_start: [u32; 2],
+    src_strides: [u32; 2],
+    dst: &mut [T],
+    dst_start: [u32; 2],
+    dst_strides: [u32; 2],
+) where
+    T: Clone,
+{
+    copy_nd_strided(
+        copy_shape,
+        src,
+        src_start,
+        src_strides,
+        dst,
+        dst_start,
+        dst_strides,
+    )
+}
+
+#[test]
+fn test_copy2_strided() {
+    // A 10x4 src buffer pitched to a row length of 12 (2 elements of padding per row).
+    const SRC_PITCH: u32 = 12;
+    const SRC_SIZE: usize = (SRC_PITCH * 4) as usize;
+    let src = [1; SRC_SIZE];
+
+    // A 10x4 dst buffer pitched to a row length of 16 (6 elements of padding per row).
+    const DST_PITCH: u32 = 16;
+    const DST_SIZE: usize = (DST_PITCH * 4) as usize;
+    let mut dst = [0; DST_SIZE];
+
+    copy2_strided(
+        [10, 4],
+        &src,
+        [0, 0],
+        [1, SRC_PITCH],
+        &mut dst,
+        [0, 0],
+        [1, DST_PITCH],
+    );
+
+    for y in 0..4 {
+        for x in 0..10 {
+            let i = (x + DST_PITCH * y) as usize;
+            assert_eq!(1, dst[i]);
+            dst[i] = 0;
+        }
+    }
+    for i in 0..DST_SIZE {
+        assert_eq!(dst[i], 0);
+    }
+}
+
+/// Copy N-dimensional data from `src` to `dst`, using `copy_from_slice` instead of
+/// `clone_from_slice` for the row transfer.
+///
+/// This is the same algorithm as [`copy_nd`], specialized for `T: Copy`. Since stable Rust lacks
+/// specialization, it's exposed as a separate function rather than an automatic fast path: callers
+/// with a `Copy` element type (e.g. `u8`/`u32`/voxel POD types) should prefer this over `copy_nd`
+/// to avoid going through `Clone` on the hot path.
+///
+/// - `copy_shape`: Dimensions of the extent to be copied.
+/// - `src`: The source slice.
+/// - `src_shape`: A `Shape<u32, N>` for the entire `src` slice.
+/// - `src_start`: The starting offset to copy from `src`.
+/// - `dst`: The destination slice.
+/// - `dst_shape`: A `Shape<u32, N>` for the entire `dst` slice.
+/// - `dst_start`: The starting offset to copy into `dst`.
+#[inline]
+pub fn copy_nd_copy<T, Src, Dst, const N: usize>(
+    copy_shape: [u32; N],
+    src: &[T],
+    src_shape: &Src,
+    src_start: [u32; N],
+    dst: &mut [T],
+    dst_shape: &Dst,
+    dst_start: [u32; N],
+) where
+    T: Copy,
+    Src: Shape<u32, N>,
+    Dst: Shape<u32, N>,
+{
+    let row_length = copy_shape[0];
+    let num_rows: u32 = copy_shape[1..].iter().product();
+
+    let mut cursor = [0u32; N];
+    for _ in 0..num_rows {
+        let mut src_coords = src_start;
+        let mut dst_coords = dst_start;
+        for axis in 1..N {
+            src_coords[axis] = src_start[axis] + cursor[axis];
+            dst_coords[axis] = dst_start[axis] + cursor[axis];
+        }
+
+        let src_row_start = src_shape.linearize(src_coords) as usize;
+        let src_row_end = src_row_start + row_length as usize;
+
+        let dst_row_start = dst_shape.linearize(dst_coords) as usize;
+        let dst_row_end = dst_row_start + row_length as usize;
+
+        dst[dst_row_start..dst_row_end].copy_from_slice(&src[src_row_start..src_row_end]);
+
+        for axis in (1..N).rev() {
+            cursor[axis] += 1;
+            if cursor[axis] < copy_shape[axis] {
+                break;
+            }
+            cursor[axis] = 0;
+        }
+    }
+}
+
+/// Copy 2-dimensional data from `src` to `dst`, using `copy_from_slice` instead of
+/// `clone_from_slice` for the row transfer.
+///
+/// See [`copy_nd_copy`] for the general N-dimensional implementation.
 ///
 /// - `copy_shape`: Dimensions of the extent to be copied.
 /// - `src`: The source slice.
@@ -42,7 +483,7 @@ use ndshape::Shape;
 /// - `dst_shape`: A `Shape<u32, 2>` for the entire `dst` slice.
 /// - `dst_start`: The starting 2D offset to copy into `dst`.
 #[inline]
-pub fn copy2<T, Src, Dst>(
+pub fn copy2_copy<T, Src, Dst>(
     copy_shape: [u32; 2],
     src: &[T],
     src_shape: &Src,
@@ -51,28 +492,76 @@ pub fn copy2<T, Src, Dst>(
     dst_shape: &Dst,
     dst_start: [u32; 2],
 ) where
-    T: Clone,
+    T: Copy,
     Src: Shape<u32, 2>,
     Dst: Shape<u32, 2>,
 {
-    let row_length = copy_shape[0];
+    copy_nd_copy(
+        copy_shape, src, src_shape, src_start, dst, dst_shape, dst_start,
+    )
+}
 
-    let mut src_y = src_start[1];
-    let mut dst_y = dst_start[1];
-    for _ in 0..copy_shape[1] {
-        let src_row_start = src_shape.linearize([src_start[0], src_y]) as usize;
-        let src_row_end = src_row_start + row_length as usize;
+#[test]
+fn test_copy2_copy() {
+    use ndshape::ConstShape2u32;
 
-        let dst_row_start = dst_shape.linearize([dst_start[0], dst_y]) as usize;
-        let dst_row_end = dst_row_start + row_length as usize;
+    let src_shape = ConstShape2u32::<10, 11>;
+    const SRC_SIZE: usize = 10 * 11;
+    let src = [1; SRC_SIZE];
+    let dst_shape = ConstShape2u32::<11, 12>;
+    const DST_SIZE: usize = 11 * 12;
+    let mut dst = [0; DST_SIZE];
 
-        dst[dst_row_start..dst_row_end].clone_from_slice(&src[src_row_start..src_row_end]);
+    copy2_copy(
+        [2, 3],
+        &src,
+        &src_shape,
+        [3, 4],
+        &mut dst,
+        &dst_shape,
+        [4, 5],
+    );
 
-        src_y += 1;
-        dst_y += 1;
+    for y in 5..5 + 3 {
+        for x in 4..4 + 2 {
+            let i = dst_shape.linearize([x, y]) as usize;
+            assert_eq!(1, dst[i]);
+            dst[i] = 0;
+        }
+    }
+    for i in 0..DST_SIZE {
+        assert_eq!(dst[i], 0);
     }
 }
 
+/// Copy 2-dimensional data from `src` to `dst`.
+///
+/// - `copy_shape`: Dimensions of the extent to be copied.
+/// - `src`: The source slice.
+/// - `src_shape`: A `Shape<u32, 2>` for the entire `src` slice.
+/// - `src_start`: The starting 2D offset to copy from `src`.
+/// - `dst`: The destination slice.
+/// - `dst_shape`: A `Shape<u32, 2>` for the entire `dst` slice.
+/// - `dst_start`: The starting 2D offset to copy into `dst`.
+#[inline]
+pub fn copy2<T, Src, Dst>(
+    copy_shape: [u32; 2],
+    src: &[T],
+    src_shape: &Src,
+    src_start: [u32; 2],
+    dst: &mut [T],
+    dst_shape: &Dst,
+    dst_start: [u32; 2],
+) where
+    T: Clone,
+    Src: Shape<u32, 2>,
+    Dst: Shape<u32, 2>,
+{
+    copy_nd(
+        copy_shape, src, src_shape, src_start, dst, dst_shape, dst_start,
+    )
+}
+
 #[test]
 fn test_copy2() {
     use ndshape::ConstShape2u32;
@@ -124,17 +613,7 @@ pub fn fill2<T, Dst>(
     T: Clone,
     Dst: Shape<u32, 2>,
 {
-    let row_length = fill_shape[0];
-
-    let mut dst_y = dst_start[1];
-    for _ in 0..fill_shape[1] {
-        let dst_row_start = dst_shape.linearize([dst_start[0], dst_y]) as usize;
-        let dst_row_end = dst_row_start + row_length as usize;
-
-        dst[dst_row_start..dst_row_end].fill(value.clone());
-
-        dst_y += 1;
-    }
+    fill_nd(fill_shape, value, dst, dst_shape, dst_start)
 }
 
 #[test]
@@ -182,32 +661,80 @@ pub fn copy3<T, Src, Dst>(
     Src: Shape<u32, 3>,
     Dst: Shape<u32, 3>,
 {
-    let row_length = copy_shape[0];
+    copy_nd(
+        copy_shape, src, src_shape, src_start, dst, dst_shape, dst_start,
+    )
+}
 
-    let mut src_z = src_start[2];
-    let mut dst_z = dst_start[2];
-    for _ in 0..copy_shape[2] {
-        let mut src_y = src_start[1];
-        let mut dst_y = dst_start[1];
-        for _ in 0..copy_shape[1] {
-            let src_row_start = src_shape.linearize([src_start[0], src_y, src_z]) as usize;
-            let src_row_end = src_row_start + row_length as usize;
+#[test]
+fn test_copy3() {
+    use ndshape::ConstShape3u32;
 
-            let dst_row_start = dst_shape.linearize([dst_start[0], dst_y, dst_z]) as usize;
-            let dst_row_end = dst_row_start + row_length as usize;
+    let src_shape = ConstShape3u32::<10, 11, 12>;
+    const SRC_SIZE: usize = 10 * 11 * 12;
+    let src = [1; SRC_SIZE];
 
-            dst[dst_row_start..dst_row_end].clone_from_slice(&src[src_row_start..src_row_end]);
+    let dst_shape = ConstShape3u32::<11, 12, 13>;
+    const DST_SIZE: usize = 11 * 12 * 13;
+    let mut dst = [0; DST_SIZE];
+
+    copy3(
+        [2, 3, 4],
+        &src,
+        &src_shape,
+        [3, 4, 5],
+        &mut dst,
+        &dst_shape,
+        [4, 5, 6],
+    );
 
-            src_y += 1;
-            dst_y += 1;
+    for z in 6..6 + 4 {
+        for y in 5..5 + 3 {
+            for x in 4..4 + 2 {
+                let i = dst_shape.linearize([x, y, z]) as usize;
+                assert_eq!(1, dst[i]);
+                dst[i] = 0;
+            }
         }
-        src_z += 1;
-        dst_z += 1;
     }
+    for i in 0..DST_SIZE {
+        assert_eq!(dst[i], 0);
+    }
+}
+
+/// Copy 3-dimensional data from `src` to `dst`, using `copy_from_slice` instead of
+/// `clone_from_slice` for the row transfer.
+///
+/// See [`copy_nd_copy`] for the general N-dimensional implementation.
+///
+/// - `copy_shape`: Dimensions of the extent to be copied.
+/// - `src`: The source slice.
+/// - `src_shape`: A `Shape<u32, 3>` for the entire `src` slice.
+/// - `src_start`: The starting 3D offset to copy from `src`.
+/// - `dst`: The destination slice.
+/// - `dst_shape`: A `Shape<u32, 3>` for the entire `dst` slice.
+/// - `dst_start`: The starting 3D offset to copy into `dst`.
+#[inline]
+pub fn copy3_copy<T, Src, Dst>(
+    copy_shape: [u32; 3],
+    src: &[T],
+    src_shape: &Src,
+    src_start: [u32; 3],
+    dst: &mut [T],
+    dst_shape: &Dst,
+    dst_start: [u32; 3],
+) where
+    T: Copy,
+    Src: Shape<u32, 3>,
+    Dst: Shape<u32, 3>,
+{
+    copy_nd_copy(
+        copy_shape, src, src_shape, src_start, dst, dst_shape, dst_start,
+    )
 }
 
 #[test]
-fn test_copy3() {
+fn test_copy3_copy() {
     use ndshape::ConstShape3u32;
 
     let src_shape = ConstShape3u32::<10, 11, 12>;
@@ -218,7 +745,7 @@ fn test_copy3() {
     const DST_SIZE: usize = 11 * 12 * 13;
     let mut dst = [0; DST_SIZE];
 
-    copy3(
+    copy3_copy(
         [2, 3, 4],
         &src,
         &src_shape,
@@ -260,21 +787,7 @@ pub fn fill3<T, Dst>(
     T: Clone,
     Dst: Shape<u32, 3>,
 {
-    let row_length = fill_shape[0];
-
-    let mut dst_z = dst_start[2];
-    for _ in 0..fill_shape[2] {
-        let mut dst_y = dst_start[1];
-        for _ in 0..fill_shape[1] {
-            let dst_row_start = dst_shape.linearize([dst_start[0], dst_y, dst_z]) as usize;
-            let dst_row_end = dst_row_start + row_length as usize;
-
-            dst[dst_row_start..dst_row_end].fill(value.clone());
-
-            dst_y += 1;
-        }
-        dst_z += 1;
-    }
+    fill_nd(fill_shape, value, dst, dst_shape, dst_start)
 }
 
 #[test]
@@ -301,6 +814,78 @@ fn test_fill3() {
     }
 }
 
+/// Copy 3-dimensional data from `src` to `dst`, where axis-0 elements may be strided.
+///
+/// See [`copy_nd_strided`] for the general N-dimensional implementation.
+///
+/// - `copy_shape`: Dimensions of the extent to be copied.
+/// - `src`: The source slice.
+/// - `src_start`: The starting 3D offset to copy from `src`, in elements.
+/// - `src_strides`: The stride of each axis in `src`, in elements.
+/// - `dst`: The destination slice.
+/// - `dst_start`: The starting 3D offset to copy into `dst`, in elements.
+/// - `dst_strides`: The stride of each axis in `dst`, in elements.
+#[inline]
+pub fn copy3_strided<T>(
+    copy_shape: [u32; 3],
+    src: &[T],
+    src_start: [u32; 3],
+    src_strides: [u32; 3],
+    dst: &mut [T],
+    dst_start: [u32; 3],
+    dst_strides: [u32; 3],
+) where
+    T: Clone,
+{
+    copy_nd_strided(
+        copy_shape,
+        src,
+        src_start,
+        src_strides,
+        dst,
+        dst_start,
+        dst_strides,
+    )
+}
+
+#[test]
+fn test_copy3_strided() {
+    // A 6x4x3 src volume pitched so each row has 2 elements of padding and each plane has 1 extra row.
+    const SRC_ROW_STRIDE: u32 = 8;
+    const SRC_PLANE_STRIDE: u32 = SRC_ROW_STRIDE * 5;
+    const SRC_SIZE: usize = (SRC_PLANE_STRIDE * 3) as usize;
+    let src = [1; SRC_SIZE];
+
+    // A contiguous 6x4x3 dst volume.
+    const DST_ROW_STRIDE: u32 = 6;
+    const DST_PLANE_STRIDE: u32 = DST_ROW_STRIDE * 4;
+    const DST_SIZE: usize = (DST_PLANE_STRIDE * 3) as usize;
+    let mut dst = [0; DST_SIZE];
+
+    copy3_strided(
+        [6, 4, 3],
+        &src,
+        [0, 0, 0],
+        [1, SRC_ROW_STRIDE, SRC_PLANE_STRIDE],
+        &mut dst,
+        [0, 0, 0],
+        [1, DST_ROW_STRIDE, DST_PLANE_STRIDE],
+    );
+
+    for z in 0..3 {
+        for y in 0..4 {
+            for x in 0..6 {
+                let i = (x + DST_ROW_STRIDE * y + DST_PLANE_STRIDE * z) as usize;
+                assert_eq!(1, dst[i]);
+                dst[i] = 0;
+            }
+        }
+    }
+    for i in 0..DST_SIZE {
+        assert_eq!(dst[i], 0);
+    }
+}
+
 /// Copy 4-dimensional data from `src` to `dst`.
 ///
 /// - `copy_shape`: Dimensions of the extent to be copied.
@@ -324,40 +909,82 @@ pub fn copy4<T, Src, Dst>(
     Src: Shape<u32, 4>,
     Dst: Shape<u32, 4>,
 {
-    let row_length = copy_shape[0];
+    copy_nd(
+        copy_shape, src, src_shape, src_start, dst, dst_shape, dst_start,
+    )
+}
+
+#[test]
+fn test_copy4() {
+    use ndshape::ConstShape4u32;
+
+    let src_shape = ConstShape4u32::<10, 11, 12, 13>;
+    const SRC_SIZE: usize = 10 * 11 * 12 * 13;
+    let src = [1; SRC_SIZE];
+
+    let dst_shape = ConstShape4u32::<11, 12, 13, 14>;
+    const DST_SIZE: usize = 11 * 12 * 13 * 14;
+    let mut dst = [0; DST_SIZE];
+
+    copy4(
+        [2, 3, 4, 5],
+        &src,
+        &src_shape,
+        [3, 4, 5, 6],
+        &mut dst,
+        &dst_shape,
+        [4, 5, 6, 7],
+    );
 
-    let mut src_w = src_start[3];
-    let mut dst_w = dst_start[3];
-    for _ in 0..copy_shape[3] {
-        let mut src_z = src_start[2];
-        let mut dst_z = dst_start[2];
-        for _ in 0..copy_shape[2] {
-            let mut src_y = src_start[1];
-            let mut dst_y = dst_start[1];
-            for _ in 0..copy_shape[1] {
-                let src_row_start =
-                    src_shape.linearize([src_start[0], src_y, src_z, src_w]) as usize;
-                let src_row_end = src_row_start + row_length as usize;
-
-                let dst_row_start =
-                    dst_shape.linearize([dst_start[0], dst_y, dst_z, dst_w]) as usize;
-                let dst_row_end = dst_row_start + row_length as usize;
-
-                dst[dst_row_start..dst_row_end].clone_from_slice(&src[src_row_start..src_row_end]);
-
-                src_y += 1;
-                dst_y += 1;
+    for w in 7..7 + 5 {
+        for z in 6..6 + 4 {
+            for y in 5..5 + 3 {
+                for x in 4..4 + 2 {
+                    let i = dst_shape.linearize([x, y, z, w]) as usize;
+                    assert_eq!(1, dst[i]);
+                    dst[i] = 0;
+                }
             }
-            src_z += 1;
-            dst_z += 1;
         }
-        src_w += 1;
-        dst_w += 1;
     }
+    for i in 0..DST_SIZE {
+        assert_eq!(dst[i], 0);
+    }
+}
+
+/// Copy 4-dimensional data from `src` to `dst`, using `copy_from_slice` instead of
+/// `clone_from_slice` for the row transfer.
+///
+/// See [`copy_nd_copy`] for the general N-dimensional implementation.
+///
+/// - `copy_shape`: Dimensions of the extent to be copied.
+/// - `src`: The source slice.
+/// - `src_shape`: A `Shape<u32, 4>` for the entire `src` slice.
+/// - `src_start`: The starting 4D offset to copy from `src`.
+/// - `dst`: The destination slice.
+/// - `dst_shape`: A `Shape<u32, 4>` for the entire `dst` slice.
+/// - `dst_start`: The starting 4D offset to copy into `dst`.
+#[inline]
+pub fn copy4_copy<T, Src, Dst>(
+    copy_shape: [u32; 4],
+    src: &[T],
+    src_shape: &Src,
+    src_start: [u32; 4],
+    dst: &mut [T],
+    dst_shape: &Dst,
+    dst_start: [u32; 4],
+) where
+    T: Copy,
+    Src: Shape<u32, 4>,
+    Dst: Shape<u32, 4>,
+{
+    copy_nd_copy(
+        copy_shape, src, src_shape, src_start, dst, dst_shape, dst_start,
+    )
 }
 
 #[test]
-fn test_copy4() {
+fn test_copy4_copy() {
     use ndshape::ConstShape4u32;
 
     let src_shape = ConstShape4u32::<10, 11, 12, 13>;
@@ -368,7 +995,7 @@ fn test_copy4() {
     const DST_SIZE: usize = 11 * 12 * 13 * 14;
     let mut dst = [0; DST_SIZE];
 
-    copy4(
+    copy4_copy(
         [2, 3, 4, 5],
         &src,
         &src_shape,
@@ -412,26 +1039,7 @@ pub fn fill4<T, Dst>(
     T: Clone,
     Dst: Shape<u32, 4>,
 {
-    let row_length = fill_shape[0];
-
-    let mut dst_w = dst_start[3];
-    for _ in 0..fill_shape[3] {
-        let mut dst_z = dst_start[2];
-        for _ in 0..fill_shape[2] {
-            let mut dst_y = dst_start[1];
-            for _ in 0..fill_shape[1] {
-                let dst_row_start =
-                    dst_shape.linearize([dst_start[0], dst_y, dst_z, dst_w]) as usize;
-                let dst_row_end = dst_row_start + row_length as usize;
-
-                dst[dst_row_start..dst_row_end].fill(value.clone());
-
-                dst_y += 1;
-            }
-            dst_z += 1;
-        }
-        dst_w += 1;
-    }
+    fill_nd(fill_shape, value, dst, dst_shape, dst_start)
 }
 
 #[test]
@@ -459,3 +1067,321 @@ fn test_fill4() {
         assert_eq!(dst[i], 0);
     }
 }
+
+/// Gather N-dimensional data from `src` into `dst`, selecting along `axis` by an arbitrary list
+/// of coordinates instead of copying a contiguous extent.
+///
+/// For each `i`-th entry of `indices`, the full sub-slab at coordinate `indices[i]` on `axis` is
+/// copied into the `i`-th consecutive slab of `dst`, starting at `dst_start`. `slab_shape` gives
+/// the full extent of every axis other than `axis` (the value at `slab_shape[axis]` is unused).
+/// This enables reordering, downsampling, and permutation of volumes (e.g. extracting specific
+/// Z-slices of a voxel chunk) without per-element indexing by the caller.
+///
+/// The row-by-row memcpy structure is preserved for the non-selected axes, so `axis` must not be
+/// the row axis (axis 0).
+///
+/// - `axis`: The dimension to gather along; must be in `1..N`.
+/// - `indices`: The coordinates to select on `axis`, in the order they should appear in `dst`.
+/// - `slab_shape`: The extent of every axis other than `axis`.
+/// - `src`: The source slice.
+/// - `src_shape`: A `Shape<u32, N>` for the entire `src` slice.
+/// - `dst`: The destination slice.
+/// - `dst_shape`: A `Shape<u32, N>` for the entire `dst` slice.
+/// - `dst_start`: The starting offset to gather into `dst`.
+#[inline]
+// The axis/indices/slab_shape parameters are each independently meaningful and don't bundle into
+// an existing type, so this stays at 8 args rather than growing a single-use struct.
+#[allow(clippy::too_many_arguments)]
+// The odometer cursor is indexed by axis, and `axis` itself must be skipped while iterating, so
+// this needs index-based iteration rather than `.iter()`/`.enumerate()`.
+#[allow(clippy::needless_range_loop)]
+pub fn gather_nd<T, Src, Dst, const N: usize>(
+    axis: usize,
+    indices: &[u32],
+    slab_shape: [u32; N],
+    src: &[T],
+    src_shape: &Src,
+    dst: &mut [T],
+    dst_shape: &Dst,
+    dst_start: [u32; N],
+) where
+    T: Clone,
+    Src: Shape<u32, N>,
+    Dst: Shape<u32, N>,
+{
+    assert!(
+        (1..N).contains(&axis),
+        "gather axis must select a non-row dimension (1..{})",
+        N
+    );
+
+    let row_length = slab_shape[0];
+    let mut rows_per_slab = 1u32;
+    for a in 1..N {
+        if a != axis {
+            rows_per_slab *= slab_shape[a];
+        }
+    }
+
+    for (pos, &gather_coord) in indices.iter().enumerate() {
+        let mut cursor = [0u32; N];
+        for _ in 0..rows_per_slab {
+            let mut src_coords = cursor;
+            let mut dst_coords = cursor;
+            src_coords[axis] = gather_coord;
+            dst_coords[0] = dst_start[0];
+            dst_coords[axis] = dst_start[axis] + pos as u32;
+            for a in 1..N {
+                if a != axis {
+                    dst_coords[a] = dst_start[a] + cursor[a];
+                }
+            }
+
+            let src_row_start = src_shape.linearize(src_coords) as usize;
+            let src_row_end = src_row_start + row_length as usize;
+
+            let dst_row_start = dst_shape.linearize(dst_coords) as usize;
+            let dst_row_end = dst_row_start + row_length as usize;
+
+            dst[dst_row_start..dst_row_end].clone_from_slice(&src[src_row_start..src_row_end]);
+
+            for a in (1..N).rev() {
+                if a == axis {
+                    continue;
+                }
+                cursor[a] += 1;
+                if cursor[a] < slab_shape[a] {
+                    break;
+                }
+                cursor[a] = 0;
+            }
+        }
+    }
+}
+
+/// Gather 2-dimensional data from `src` into `dst`, selecting rows by an arbitrary list of
+/// Y-coordinates instead of copying a contiguous extent.
+///
+/// See [`gather_nd`] for the general N-dimensional implementation.
+///
+/// - `indices`: The Y-coordinates to select, in the order they should appear in `dst`.
+/// - `slab_shape`: `[row_length, _]`; the Y extent is unused since rows are selected by `indices`.
+/// - `src`: The source slice.
+/// - `src_shape`: A `Shape<u32, 2>` for the entire `src` slice.
+/// - `dst`: The destination slice.
+/// - `dst_shape`: A `Shape<u32, 2>` for the entire `dst` slice.
+/// - `dst_start`: The starting 2D offset to gather into `dst`.
+#[inline]
+pub fn gather2<T, Src, Dst>(
+    indices: &[u32],
+    slab_shape: [u32; 2],
+    src: &[T],
+    src_shape: &Src,
+    dst: &mut [T],
+    dst_shape: &Dst,
+    dst_start: [u32; 2],
+) where
+    T: Clone,
+    Src: Shape<u32, 2>,
+    Dst: Shape<u32, 2>,
+{
+    gather_nd(
+        1, indices, slab_shape, src, src_shape, dst, dst_shape, dst_start,
+    )
+}
+
+#[test]
+fn test_gather2() {
+    use ndshape::ConstShape2u32;
+
+    let src_shape = ConstShape2u32::<4, 5>;
+    const SRC_SIZE: usize = 4 * 5;
+    // Row y is filled with the value y, so the gathered rows can be checked by value.
+    let mut src = [0u32; SRC_SIZE];
+    for y in 0..5 {
+        for x in 0..4 {
+            src[src_shape.linearize([x, y]) as usize] = y;
+        }
+    }
+
+    let dst_shape = ConstShape2u32::<4, 3>;
+    const DST_SIZE: usize = 4 * 3;
+    let mut dst = [99u32; DST_SIZE];
+
+    gather2(
+        &[3, 0, 4],
+        [4, 0],
+        &src,
+        &src_shape,
+        &mut dst,
+        &dst_shape,
+        [0, 0],
+    );
+
+    for (pos, &expected_row) in [3u32, 0, 4].iter().enumerate() {
+        for x in 0..4 {
+            let i = dst_shape.linearize([x, pos as u32]) as usize;
+            assert_eq!(dst[i], expected_row);
+        }
+    }
+}
+
+#[test]
+fn test_gather2_with_nonzero_dst_start() {
+    use ndshape::ConstShape2u32;
+
+    let src_shape = ConstShape2u32::<4, 5>;
+    const SRC_SIZE: usize = 4 * 5;
+    let mut src = [0u32; SRC_SIZE];
+    for y in 0..5 {
+        for x in 0..4 {
+            src[src_shape.linearize([x, y]) as usize] = y;
+        }
+    }
+
+    // Columns 0..2 of dst are untouched padding; the gathered rows should land at columns 2..6.
+    let dst_shape = ConstShape2u32::<6, 3>;
+    const DST_SIZE: usize = 6 * 3;
+    let mut dst = [99u32; DST_SIZE];
+
+    gather2(
+        &[3, 0, 4],
+        [4, 0],
+        &src,
+        &src_shape,
+        &mut dst,
+        &dst_shape,
+        [2, 0],
+    );
+
+    for (pos, &expected_row) in [3u32, 0, 4].iter().enumerate() {
+        for x in 0..2 {
+            let i = dst_shape.linearize([x, pos as u32]) as usize;
+            assert_eq!(dst[i], 99);
+        }
+        for x in 2..6 {
+            let i = dst_shape.linearize([x, pos as u32]) as usize;
+            assert_eq!(dst[i], expected_row);
+        }
+    }
+}
+
+/// Gather 3-dimensional data from `src` into `dst`, selecting planes by an arbitrary list of
+/// coordinates on `axis` instead of copying a contiguous extent.
+///
+/// See [`gather_nd`] for the general N-dimensional implementation.
+///
+/// - `axis`: The dimension to gather along; must be `1` or `2`.
+/// - `indices`: The coordinates to select on `axis`, in the order they should appear in `dst`.
+/// - `slab_shape`: The extent of every axis other than `axis`.
+/// - `src`: The source slice.
+/// - `src_shape`: A `Shape<u32, 3>` for the entire `src` slice.
+/// - `dst`: The destination slice.
+/// - `dst_shape`: A `Shape<u32, 3>` for the entire `dst` slice.
+/// - `dst_start`: The starting 3D offset to gather into `dst`.
+#[inline]
+#[allow(clippy::too_many_arguments)]
+pub fn gather3<T, Src, Dst>(
+    axis: usize,
+    indices: &[u32],
+    slab_shape: [u32; 3],
+    src: &[T],
+    src_shape: &Src,
+    dst: &mut [T],
+    dst_shape: &Dst,
+    dst_start: [u32; 3],
+) where
+    T: Clone,
+    Src: Shape<u32, 3>,
+    Dst: Shape<u32, 3>,
+{
+    gather_nd(
+        axis, indices, slab_shape, src, src_shape, dst, dst_shape, dst_start,
+    )
+}
+
+#[test]
+fn test_gather3_z_slices() {
+    use ndshape::ConstShape3u32;
+
+    let src_shape = ConstShape3u32::<4, 3, 6>;
+    const SRC_SIZE: usize = 4 * 3 * 6;
+    // Z-slice z is filled with the value z, so the gathered slices can be checked by value.
+    let mut src = [0u32; SRC_SIZE];
+    for z in 0..6 {
+        for y in 0..3 {
+            for x in 0..4 {
+                src[src_shape.linearize([x, y, z]) as usize] = z;
+            }
+        }
+    }
+
+    let dst_shape = ConstShape3u32::<4, 3, 3>;
+    const DST_SIZE: usize = 4 * 3 * 3;
+    let mut dst = [99u32; DST_SIZE];
+
+    gather3(
+        2,
+        &[5, 1, 2],
+        [4, 3, 0],
+        &src,
+        &src_shape,
+        &mut dst,
+        &dst_shape,
+        [0, 0, 0],
+    );
+
+    for (pos, &expected_plane) in [5u32, 1, 2].iter().enumerate() {
+        for y in 0..3 {
+            for x in 0..4 {
+                let i = dst_shape.linearize([x, y, pos as u32]) as usize;
+                assert_eq!(dst[i], expected_plane);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gather3_z_slices_with_nonzero_dst_start() {
+    use ndshape::ConstShape3u32;
+
+    let src_shape = ConstShape3u32::<4, 3, 6>;
+    const SRC_SIZE: usize = 4 * 3 * 6;
+    let mut src = [0u32; SRC_SIZE];
+    for z in 0..6 {
+        for y in 0..3 {
+            for x in 0..4 {
+                src[src_shape.linearize([x, y, z]) as usize] = z;
+            }
+        }
+    }
+
+    // Columns 0..2 of dst are untouched padding; the gathered slices should land at columns 2..6.
+    let dst_shape = ConstShape3u32::<6, 3, 3>;
+    const DST_SIZE: usize = 6 * 3 * 3;
+    let mut dst = [99u32; DST_SIZE];
+
+    gather3(
+        2,
+        &[5, 1, 2],
+        [4, 3, 0],
+        &src,
+        &src_shape,
+        &mut dst,
+        &dst_shape,
+        [2, 0, 0],
+    );
+
+    for (pos, &expected_plane) in [5u32, 1, 2].iter().enumerate() {
+        for y in 0..3 {
+            for x in 0..2 {
+                let i = dst_shape.linearize([x, y, pos as u32]) as usize;
+                assert_eq!(dst[i], 99);
+            }
+            for x in 2..6 {
+                let i = dst_shape.linearize([x, y, pos as u32]) as usize;
+                assert_eq!(dst[i], expected_plane);
+            }
+        }
+    }
+}